@@ -1,6 +1,88 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Returns the number of leading bits shared by two IPv4 addresses.
+///
+/// Identical addresses share all 32 bits.
+pub fn common_prefix_bits_v4(a: Ipv4Addr, b: Ipv4Addr) -> u8 {
+    (u32::from(a) ^ u32::from(b)).leading_zeros() as u8
+}
+
+/// Returns the number of leading bits shared by two IPv6 addresses.
+///
+/// Identical addresses share all 128 bits.
+pub fn common_prefix_bits_v6(a: Ipv6Addr, b: Ipv6Addr) -> u8 {
+    (u128::from(a) ^ u128::from(b)).leading_zeros() as u8
+}
+
+/// Returns true if `[start, end]` is exactly one aligned IPv4 CIDR block.
+///
+/// That is, `start` is a network address, the interval size is a power of
+/// two and `end` is the matching broadcast address.
+pub fn is_cidr_block_v4(start: Ipv4Addr, end: Ipv4Addr) -> bool {
+    let start = u32::from(start);
+    let end = u32::from(end);
+    if start > end {
+        return false;
+    }
+    let hostmask = end - start;
+    hostmask & hostmask.wrapping_add(1) == 0 && start & hostmask == 0
+}
+
+/// Returns true if `[start, end]` is exactly one aligned IPv6 CIDR block.
+///
+/// That is, `start` is a network address, the interval size is a power of
+/// two and `end` is the last address of that block.
+pub fn is_cidr_block_v6(start: Ipv6Addr, end: Ipv6Addr) -> bool {
+    let start = u128::from(start);
+    let end = u128::from(end);
+    if start > end {
+        return false;
+    }
+    let hostmask = end - start;
+    hostmask & hostmask.wrapping_add(1) == 0 && start & hostmask == 0
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn it_works() {
     }
+
+    #[test]
+    fn common_prefix_bits_v4_test() {
+        assert_eq!(common_prefix_bits_v4(Ipv4Addr::new(10, 0, 0, 0), Ipv4Addr::new(10, 0, 1, 0)), 23);
+        assert_eq!(common_prefix_bits_v4(Ipv4Addr::new(10, 0, 0, 0), Ipv4Addr::new(10, 0, 0, 0)), 32);
+        assert_eq!(common_prefix_bits_v4(Ipv4Addr::new(0, 0, 0, 0), Ipv4Addr::new(128, 0, 0, 0)), 0);
+    }
+
+    #[test]
+    fn common_prefix_bits_v6_test() {
+        let a = "2001:db8::".parse().unwrap();
+        let b = "2001:db8:0:100::".parse().unwrap();
+        assert_eq!(common_prefix_bits_v6(a, b), 55);
+        assert_eq!(common_prefix_bits_v6(a, a), 128);
+        assert_eq!(common_prefix_bits_v6("::".parse().unwrap(), "8000::".parse().unwrap()), 0);
+    }
+
+    #[test]
+    fn is_cidr_block_v4_test() {
+        assert!(is_cidr_block_v4(Ipv4Addr::new(10, 0, 0, 0), Ipv4Addr::new(10, 0, 0, 255)));
+        assert!(is_cidr_block_v4(Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 1)));
+        assert!(is_cidr_block_v4(Ipv4Addr::new(0, 0, 0, 0), Ipv4Addr::new(255, 255, 255, 255)));
+        assert!(!is_cidr_block_v4(Ipv4Addr::new(10, 0, 0, 0), Ipv4Addr::new(10, 0, 0, 200)));
+        assert!(!is_cidr_block_v4(Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 2)));
+        assert!(!is_cidr_block_v4(Ipv4Addr::new(10, 0, 0, 255), Ipv4Addr::new(10, 0, 0, 0)));
+    }
+
+    #[test]
+    fn is_cidr_block_v6_test() {
+        assert!(is_cidr_block_v6("2001:db8::".parse().unwrap(), "2001:db8::ffff".parse().unwrap()));
+        assert!(is_cidr_block_v6("2001:db8::1".parse().unwrap(), "2001:db8::1".parse().unwrap()));
+        assert!(is_cidr_block_v6("::".parse().unwrap(), "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff".parse().unwrap()));
+        assert!(!is_cidr_block_v6("2001:db8::".parse().unwrap(), "2001:db8::c8".parse().unwrap()));
+        assert!(!is_cidr_block_v6("2001:db8::1".parse().unwrap(), "2001:db8::2".parse().unwrap()));
+        assert!(!is_cidr_block_v6("2001:db8::ff".parse().unwrap(), "2001:db8::".parse().unwrap()));
+    }
 }